@@ -2,43 +2,184 @@ use std::env;
 use std::fs;
 use std::path::Path;
 
-fn main() {
-    let out_dir = env::var("OUT_DIR").unwrap();
-    let dest_path = Path::new(&out_dir).join("slides.rs");
+use appcui::prelude::*;
+use syntect::easy::HighlightLines;
+use syntect::highlighting::{Color as SyntectColor, ThemeSet};
+use syntect::parsing::SyntaxSet;
+use syntect::util::LinesWithEndings;
+
+const SLIDES_SRC_DIR: &str = "src/surfaces";
+const SLIDES_OUT_DIR: &str = "static/slides";
+const CODE_EXTENSIONS: &[&str] = &["rs", "py", "js", "ts", "go", "c", "cpp"];
 
-    let slides_dir = "src/surfaces";
+fn main() {
+    fs::create_dir_all(SLIDES_OUT_DIR).unwrap();
 
     let mut slide_files = Vec::new();
 
-    if let Ok(entries) = fs::read_dir(slides_dir) {
-        let entries = entries.flatten();
-        for entry in entries {
-            let path = entry.path();
-            if path.extension().and_then(|s| s.to_str()) == Some("srf") {
-                if let Some(file_name) = path.file_name().and_then(|s| s.to_str()) {
-                    slide_files.push(file_name.to_string());
-                }
+    if let Ok(entries) = fs::read_dir(SLIDES_SRC_DIR) {
+        let mut paths: Vec<_> = entries.flatten().map(|e| e.path()).collect();
+        paths.sort();
+
+        let syntax_set = SyntaxSet::load_defaults_newlines();
+        let theme_set = ThemeSet::load_defaults();
+        let theme = &theme_set.themes["base16-ocean.dark"];
+
+        for path in paths {
+            let Some(extension) = path.extension().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            let Some(file_name) = path.file_name().and_then(|s| s.to_str()) else {
+                continue;
+            };
+
+            if extension == "srf" {
+                let out_path = Path::new(SLIDES_OUT_DIR).join(file_name);
+                fs::copy(&path, &out_path).unwrap();
+                slide_files.push(file_name.to_string());
+            } else if extension == "graph" {
+                let source = fs::read_to_string(&path).unwrap();
+                let (nodes, edges) = parse_graph_file(&source);
+
+                let out_name = format!("{file_name}.json");
+                let out_path = Path::new(SLIDES_OUT_DIR).join(&out_name);
+                let json = serde_json::json!({ "nodes": nodes, "edges": edges });
+                fs::write(&out_path, serde_json::to_string(&json).unwrap()).unwrap();
+                slide_files.push(out_name);
+            } else if CODE_EXTENSIONS.contains(&extension) {
+                let source = fs::read_to_string(&path).unwrap();
+                let syntax = syntax_set
+                    .find_syntax_by_extension(extension)
+                    .unwrap_or_else(|| syntax_set.find_syntax_plain_text());
+
+                let surface = highlight_to_surface(&source, syntax, &syntax_set, theme);
+
+                let out_name = format!("{file_name}.srf");
+                let out_path = Path::new(SLIDES_OUT_DIR).join(&out_name);
+                fs::write(&out_path, surface.to_buffer().unwrap()).unwrap();
+                slide_files.push(out_name);
             }
         }
     }
 
     slide_files.sort();
 
-    let mut code = String::new();
-    code.push_str("pub fn get_slides() -> Vec<Vec<u8>> {\n");
-    code.push_str("    vec![\n");
+    let manifest = serde_json::to_string_pretty(&slide_files).unwrap();
+    fs::write(
+        Path::new(SLIDES_OUT_DIR).join("manifest.json"),
+        manifest,
+    )
+    .unwrap();
+
+    println!("cargo:rerun-if-changed={SLIDES_SRC_DIR}");
+}
+
+fn highlight_to_surface(
+    source: &str,
+    syntax: &syntect::parsing::SyntaxReference,
+    syntax_set: &SyntaxSet,
+    theme: &syntect::highlighting::Theme,
+) -> Surface {
+    let line_count = source.lines().count().max(1);
+    let width = source.lines().map(str::len).max().unwrap_or(80).max(80) as u32;
+    let mut surface = Surface::new(width, line_count as u32);
+    surface.clear(Character::new(' ', Color::White, Color::Black, CharFlags::None));
+
+    let mut highlighter = HighlightLines::new(syntax, theme);
 
-    for file in &slide_files {
-        code.push_str(&format!(
-            "        include_bytes!(\"D:/licenta/prezentare_licenta/src/surfaces/{}\").to_vec(),\n",
-            file
-        ));
+    for (row, line) in LinesWithEndings::from(source).enumerate() {
+        let ranges = highlighter.highlight_line(line, syntax_set).unwrap();
+        let mut col = 0i32;
+        for (style, text) in ranges {
+            let color = map_color(style.foreground);
+            for ch in text.chars() {
+                if ch == '\n' || ch == '\r' {
+                    continue;
+                }
+                surface.write_char(
+                    col,
+                    row as i32,
+                    Character::new(ch, color, Color::Black, CharFlags::None),
+                );
+                col += 1;
+            }
+        }
     }
 
-    code.push_str("    ]\n");
-    code.push_str("}\n");
+    surface
+}
 
-    fs::write(&dest_path, code).unwrap();
+// .graph format: a NODES section listing labels, an EDGES section listing
+// index pairs, blank lines and #-comments ignored. Positions aren't part of
+// the file; the runtime layout picks starting positions and relaxes them.
+fn parse_graph_file(source: &str) -> (Vec<String>, Vec<(usize, usize)>) {
+    let mut nodes = Vec::new();
+    let mut edges = Vec::new();
+    let mut in_edges = false;
 
-    println!("cargo:rerun-if-changed=src/slides");
+    for line in source.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        match line {
+            "NODES" => in_edges = false,
+            "EDGES" => in_edges = true,
+            _ if in_edges => {
+                let mut parts = line.split_whitespace();
+                let (Some(a), Some(b)) = (parts.next(), parts.next()) else {
+                    continue;
+                };
+                if let (Ok(a), Ok(b)) = (a.parse(), b.parse()) {
+                    edges.push((a, b));
+                }
+            }
+            label => nodes.push(label.to_string()),
+        }
+    }
+
+    (nodes, edges)
+}
+
+fn map_color(c: SyntectColor) -> Color {
+    match (c.r, c.g, c.b) {
+        (r, g, b) if r > 180 && g < 120 && b < 120 => Color::Red,
+        (r, g, b) if g > 150 && r < 150 && b < 150 => Color::Green,
+        (r, g, b) if b > 150 && r < 150 && g < 150 => Color::Blue,
+        (r, g, b) if r > 180 && g > 180 && b < 150 => Color::Yellow,
+        (r, g, b) if r > 180 && b > 180 && g < 150 => Color::Magenta,
+        (r, g, b) if g > 150 && b > 150 && r < 150 => Color::Aqua,
+        (r, g, b) if r > 200 && g > 200 && b > 200 => Color::White,
+        (r, g, b) if r < 90 && g < 90 && b < 90 => Color::DarkGray,
+        _ => Color::Gray,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_graph_file_reads_nodes_and_edges() {
+        let source = "NODES\nAlice\nBob\nCarol\nEDGES\n0 1\n1 2\n";
+        let (nodes, edges) = parse_graph_file(source);
+        assert_eq!(nodes, vec!["Alice", "Bob", "Carol"]);
+        assert_eq!(edges, vec![(0, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn parse_graph_file_ignores_blank_lines_and_comments() {
+        let source = "NODES\n# people\nAlice\n\nBob\nEDGES\n# friendship\n0 1\n";
+        let (nodes, edges) = parse_graph_file(source);
+        assert_eq!(nodes, vec!["Alice", "Bob"]);
+        assert_eq!(edges, vec![(0, 1)]);
+    }
+
+    #[test]
+    fn parse_graph_file_skips_malformed_edge_lines() {
+        let source = "NODES\nAlice\nBob\nEDGES\n0 1\nnot-a-number 1\n0\n";
+        let (nodes, edges) = parse_graph_file(source);
+        assert_eq!(nodes, vec!["Alice", "Bob"]);
+        assert_eq!(edges, vec![(0, 1)]);
+    }
 }
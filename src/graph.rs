@@ -0,0 +1,259 @@
+use std::f32::consts::TAU;
+use std::time::Duration;
+
+use appcui::prelude::*;
+use serde::{Deserialize, Serialize};
+// std::time::Instant::now() panics on wasm32-unknown-unknown; this is backed
+// by window.performance().now() instead.
+use web_time::Instant;
+
+// Parsed by build.rs from a .graph source file into .graph.json. Edge indices
+// are not checked against the node list there; tick() and draw() skip edges
+// that point past it.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct GraphDef {
+    pub(crate) nodes: Vec<String>,
+    pub(crate) edges: Vec<(usize, usize)>,
+}
+
+struct GraphNode {
+    label: String,
+    x: f32,
+    y: f32,
+    dx: f32,
+    dy: f32,
+}
+
+pub(crate) struct GraphSlide {
+    nodes: Vec<GraphNode>,
+    edges: Vec<(usize, usize)>,
+    temperature: f32,
+    initial_temperature: f32,
+    started_at: Instant,
+    duration: Duration,
+    area_width: f32,
+    area_height: f32,
+}
+
+impl GraphSlide {
+    // Nodes start unplaced; the first tick() seeds them from the real area.
+    pub(crate) fn new(def: &GraphDef) -> Self {
+        let nodes = def
+            .nodes
+            .iter()
+            .map(|label| GraphNode {
+                label: label.clone(),
+                x: 0.0,
+                y: 0.0,
+                dx: 0.0,
+                dy: 0.0,
+            })
+            .collect();
+
+        Self {
+            nodes,
+            edges: def.edges.clone(),
+            temperature: 0.0,
+            initial_temperature: 0.0,
+            started_at: Instant::now(),
+            duration: Duration::from_secs(3),
+            area_width: 0.0,
+            area_height: 0.0,
+        }
+    }
+
+    pub(crate) fn is_animating(&self) -> bool {
+        self.temperature > 0.0
+    }
+
+    fn seed(&mut self, area_width: f32, area_height: f32) {
+        let n = self.nodes.len().max(1);
+        let radius = (area_width.min(area_height) / 2.0 - 2.0).max(1.0);
+        let center_x = area_width / 2.0;
+        let center_y = area_height / 2.0;
+
+        for (i, node) in self.nodes.iter_mut().enumerate() {
+            let angle = (i as f32 / n as f32) * TAU;
+            node.x = center_x + radius * angle.cos();
+            node.y = center_y + radius * angle.sin();
+        }
+
+        self.initial_temperature = area_width.min(area_height) / 10.0;
+        self.temperature = self.initial_temperature;
+        self.started_at = Instant::now();
+        self.area_width = area_width;
+        self.area_height = area_height;
+    }
+
+    // Re-seeds on resize, then runs one Fruchterman-Reingold iteration:
+    // repulsion between every pair of nodes, attraction along edges, capped
+    // displacement cooling to zero over self.duration.
+    pub(crate) fn tick(&mut self, area_width: f32, area_height: f32) {
+        if self.area_width != area_width || self.area_height != area_height {
+            self.seed(area_width, area_height);
+        }
+
+        if !self.is_animating() {
+            return;
+        }
+
+        let n = self.nodes.len();
+        if n == 0 {
+            return;
+        }
+
+        let area = self.area_width * self.area_height;
+        let k = (area / n as f32).sqrt();
+
+        for node in &mut self.nodes {
+            node.dx = 0.0;
+            node.dy = 0.0;
+        }
+
+        for i in 0..n {
+            for j in 0..n {
+                if i == j {
+                    continue;
+                }
+                let dx = self.nodes[i].x - self.nodes[j].x;
+                let dy = self.nodes[i].y - self.nodes[j].y;
+                let dist = (dx * dx + dy * dy).sqrt().max(0.01);
+                let force = (k * k) / dist;
+                self.nodes[i].dx += (dx / dist) * force;
+                self.nodes[i].dy += (dy / dist) * force;
+            }
+        }
+
+        for &(a, b) in &self.edges {
+            if a >= n || b >= n {
+                continue;
+            }
+            let dx = self.nodes[a].x - self.nodes[b].x;
+            let dy = self.nodes[a].y - self.nodes[b].y;
+            let dist = (dx * dx + dy * dy).sqrt().max(0.01);
+            let force = (dist * dist) / k;
+            let fx = (dx / dist) * force;
+            let fy = (dy / dist) * force;
+            self.nodes[a].dx -= fx;
+            self.nodes[a].dy -= fy;
+            self.nodes[b].dx += fx;
+            self.nodes[b].dy += fy;
+        }
+
+        for node in &mut self.nodes {
+            let disp = (node.dx * node.dx + node.dy * node.dy).sqrt().max(0.01);
+            let capped = disp.min(self.temperature);
+            node.x = (node.x + (node.dx / disp) * capped).clamp(0.0, self.area_width);
+            node.y = (node.y + (node.dy / disp) * capped).clamp(0.0, self.area_height);
+        }
+
+        let elapsed = self.started_at.elapsed().as_secs_f32();
+        let fraction = (elapsed / self.duration.as_secs_f32()).min(1.0);
+        self.temperature = self.initial_temperature * (1.0 - fraction);
+        if self.temperature < 0.0 {
+            self.temperature = 0.0;
+        }
+    }
+
+    pub(crate) fn draw(&self, surface: &mut Surface, origin_x: i32, origin_y: i32) {
+        for &(a, b) in &self.edges {
+            if a >= self.nodes.len() || b >= self.nodes.len() {
+                continue;
+            }
+            self.draw_edge(surface, origin_x, origin_y, &self.nodes[a], &self.nodes[b]);
+        }
+
+        for node in &self.nodes {
+            let x = origin_x + node.x.round() as i32;
+            let y = origin_y + node.y.round() as i32;
+            surface.write_string(
+                x,
+                y,
+                &format!("({})", node.label),
+                CharAttribute::with_color(Color::Aqua, Color::Black),
+                false,
+            );
+        }
+    }
+
+    fn draw_edge(
+        &self,
+        surface: &mut Surface,
+        origin_x: i32,
+        origin_y: i32,
+        a: &GraphNode,
+        b: &GraphNode,
+    ) {
+        let steps = ((a.x - b.x).abs().max((a.y - b.y).abs())).ceil().max(1.0) as i32;
+        for step in 0..=steps {
+            let t = step as f32 / steps as f32;
+            let x = origin_x + (a.x + (b.x - a.x) * t).round() as i32;
+            let y = origin_y + (a.y + (b.y - a.y) * t).round() as i32;
+            surface.write_char(
+                x,
+                y,
+                Character::new('.', Color::DarkGray, Color::Black, CharFlags::None),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn def(n: usize, edges: Vec<(usize, usize)>) -> GraphDef {
+        GraphDef {
+            nodes: (0..n).map(|i| format!("n{i}")).collect(),
+            edges,
+        }
+    }
+
+    #[test]
+    fn new_places_nodes_unseeded_and_not_animating() {
+        let slide = GraphSlide::new(&def(3, vec![]));
+        assert_eq!(slide.nodes.len(), 3);
+        assert!(!slide.is_animating());
+    }
+
+    #[test]
+    fn tick_seeds_nodes_around_a_circle_on_first_call() {
+        let mut slide = GraphSlide::new(&def(4, vec![(0, 1), (1, 2)]));
+        slide.tick(100.0, 50.0);
+        assert!(slide.is_animating());
+
+        // Node 0 is seeded at (50 + radius, 25); the same tick then runs one
+        // iteration, moving it one step capped at the initial temperature (5.0).
+        let radius = (100.0f32.min(50.0) / 2.0 - 2.0).max(1.0);
+        assert!((slide.nodes[0].x - (50.0 + radius + 4.718)).abs() < 1e-3);
+        assert!((slide.nodes[0].y - (25.0 + 1.654)).abs() < 1e-3);
+    }
+
+    #[test]
+    fn tick_reseeds_and_restarts_temperature_when_area_changes() {
+        let mut slide = GraphSlide::new(&def(2, vec![(0, 1)]));
+        slide.tick(100.0, 50.0);
+        slide.temperature = 0.0;
+        slide.tick(100.0, 50.0);
+        assert_eq!(slide.temperature, 0.0);
+
+        slide.tick(200.0, 80.0);
+        assert!(slide.temperature > 0.0);
+    }
+
+    #[test]
+    fn tick_moves_nodes_by_simulated_forces() {
+        let mut slide = GraphSlide::new(&def(3, vec![(0, 1), (1, 2)]));
+        slide.tick(100.0, 60.0);
+        let before: Vec<(f32, f32)> = slide.nodes.iter().map(|n| (n.x, n.y)).collect();
+
+        slide.tick(100.0, 60.0);
+        let after: Vec<(f32, f32)> = slide.nodes.iter().map(|n| (n.x, n.y)).collect();
+
+        assert_ne!(before, after);
+        for (x, y) in after {
+            assert!((0.0..=100.0).contains(&x));
+            assert!((0.0..=60.0).contains(&y));
+        }
+    }
+}
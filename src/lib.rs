@@ -1,69 +1,163 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::time::Duration;
+
 use appcui::prelude::*;
 use wasm_bindgen::prelude::*;
+// std::time::Instant::now() has no clock source on wasm32-unknown-unknown and
+// panics at runtime; web_time::Instant is a drop-in replacement backed by
+// window.performance().now().
+use web_time::Instant;
+
+mod graph;
+mod overlay;
+mod remote;
+mod slide_loader;
+
+use graph::GraphSlide;
+use overlay::{Overlay, OVERVIEW_COLUMNS};
 
-include!(concat!(env!("OUT_DIR"), "/slides.rs"));
+// Below this width, presenter mode falls back to the single-pane layout.
+const PRESENTER_DUAL_PANE_MIN_WIDTH: u32 = 100;
 
-struct PresentationData {
-    slides: Vec<Surface>,
+pub(crate) enum Slide {
+    Static(Surface),
+    Graph(GraphSlide),
+}
+
+pub(crate) struct PresentationData {
+    slides: Vec<Slide>,
     current_slide: usize,
+    presenter_mode: bool,
+    start_instant: Instant,
+    slide_start_instant: Instant,
+    overlay: Overlay,
 }
 
 impl PresentationData {
     fn new() -> Self {
-        let mut slides = Vec::new();
-
-        let slide_contents = get_slides();
-
-        for slide_content in slide_contents.iter() {
-            match Surface::from_buffer(slide_content) {
-                Ok(srf) => slides.push(srf),
-                Err(e) => {
-                    web_sys::console::error_1(
-                        &format!("Failed to load slide '{slide_content:?}': {e}").into(),
-                    );
-                }
-            }
-        }
-
+        let now = Instant::now();
         Self {
-            slides,
+            slides: Vec::new(),
             current_slide: 0,
+            presenter_mode: false,
+            start_instant: now,
+            slide_start_instant: now,
+            overlay: Overlay::None,
         }
     }
-    fn next_slide(&mut self) -> bool {
-        if self.current_slide + 1 < self.slides.len() {
-            self.current_slide += 1;
+    pub(crate) fn push_slide(&mut self, slide: Slide) {
+        self.slides.push(slide);
+    }
+    pub(crate) fn goto_slide(&mut self, index: usize) -> bool {
+        if index < self.slides.len() && index != self.current_slide {
+            self.current_slide = index;
+            self.slide_start_instant = Instant::now();
             true
         } else {
             false
         }
     }
-    fn prev_slide(&mut self) -> bool {
+    pub(crate) fn next_slide(&mut self) -> bool {
+        if self.current_slide + 1 < self.slides.len() {
+            self.goto_slide(self.current_slide + 1)
+        } else {
+            false
+        }
+    }
+    pub(crate) fn prev_slide(&mut self) -> bool {
         if self.current_slide > 0 {
-            self.current_slide -= 1;
-            true
+            self.goto_slide(self.current_slide - 1)
         } else {
             false
         }
     }
-    fn current_slide_content(&self) -> Option<&Surface> {
+    fn current_slide(&self) -> Option<&Slide> {
         self.slides.get(self.current_slide)
     }
+    fn current_slide_mut(&mut self) -> Option<&mut Slide> {
+        self.slides.get_mut(self.current_slide)
+    }
+    fn next_slide_preview(&self) -> Option<&Slide> {
+        self.slides.get(self.current_slide + 1)
+    }
+    pub(crate) fn tick_current_slide(&mut self, area_width: f32, area_height: f32) {
+        if let Some(Slide::Graph(g)) = self.current_slide_mut() {
+            g.tick(area_width, area_height);
+        }
+    }
     fn slide_info(&self) -> String {
         format!("{}/{}", self.current_slide + 1, self.slides.len())
     }
+    pub(crate) fn slide_count(&self) -> usize {
+        self.slides.len()
+    }
+    // 1-based, for RemoteMessage::SlideInfo.
+    pub(crate) fn slide_position(&self) -> (usize, usize) {
+        (self.current_slide + 1, self.slides.len())
+    }
+    fn elapsed_total(&self) -> Duration {
+        self.start_instant.elapsed()
+    }
+    fn elapsed_current_slide(&self) -> Duration {
+        self.slide_start_instant.elapsed()
+    }
+    fn toggle_presenter_mode(&mut self) {
+        self.presenter_mode = !self.presenter_mode;
+    }
+    pub(crate) fn overlay(&self) -> Overlay {
+        self.overlay
+    }
+    pub(crate) fn open_overview(&mut self) {
+        self.overlay = Overlay::Overview {
+            selected: self.current_slide,
+        };
+    }
+    pub(crate) fn open_help(&mut self) {
+        self.overlay = Overlay::Help;
+    }
+    pub(crate) fn close_overlay(&mut self) -> bool {
+        let was_open = self.overlay != Overlay::None;
+        self.overlay = Overlay::None;
+        was_open
+    }
+    pub(crate) fn move_overview_selection(&mut self, delta: isize) -> bool {
+        let Overlay::Overview { selected } = &mut self.overlay else {
+            return false;
+        };
+        let moved = overlay::move_selection(*selected, self.slides.len(), delta);
+        if moved == *selected {
+            return false;
+        }
+        *selected = moved;
+        true
+    }
+    pub(crate) fn confirm_overview_selection(&mut self) -> bool {
+        let Overlay::Overview { selected } = self.overlay else {
+            return false;
+        };
+        self.overlay = Overlay::None;
+        self.goto_slide(selected);
+        true
+    }
+}
+
+fn format_duration(d: Duration) -> String {
+    let total_secs = d.as_secs();
+    format!("{:02}:{:02}", total_secs / 60, total_secs % 60)
 }
 
 #[CustomControl(overwrite = OnPaint + OnKeyPressed)]
 struct PresentationControl {
-    data: PresentationData,
+    data: Rc<RefCell<PresentationData>>,
 }
 
 impl PresentationControl {
-    pub fn new(layout: Layout) -> Self {
+    pub fn new(layout: Layout, data: Rc<RefCell<PresentationData>>) -> Self {
+        slide_loader::spawn_slide_loader(data.clone());
         Self {
             base: ControlBase::new(layout, true),
-            data: PresentationData::new(),
+            data,
         }
     }
 }
@@ -77,25 +171,50 @@ impl OnPaint for PresentationControl {
             CharFlags::None,
         ));
 
-        let Some(content) = self.data.current_slide_content() else {
-            web_sys::console::error_1(&"No content available for the current slide".into());
+        let sz = surface.size();
+        // Footer (slide counter / help / timers) reserves the bottom rows,
+        // matching the region `paint_presenter_dual_pane` draws its divider
+        // into.
+        let drawable_height = (sz.height as f32 - 3.0).max(1.0);
+        self.data
+            .borrow_mut()
+            .tick_current_slide(sz.width as f32, drawable_height);
+
+        let data = self.data.borrow();
+
+        match data.overlay() {
+            Overlay::Overview { selected } => {
+                overlay::paint_overview(surface, &data.slides, selected);
+                return;
+            }
+            Overlay::Help => {
+                overlay::paint_help(surface);
+                return;
+            }
+            Overlay::None => {}
+        }
+
+        let Some(slide) = data.current_slide() else {
+            surface.write_string(
+                2,
+                2,
+                "Loading slides...",
+                CharAttribute::with_color(Color::Gray, Color::Black),
+                false,
+            );
             return;
         };
 
-        surface.draw_surface(0, 0, content);
-        // for (i, line) in content.iter().enumerate() {
-        //     surface.write_string(
-        //         0,
-        //         i as i32,
-        //         line,
-        //         CharAttribute::with_color(Color::White, Color::Black),
-        //         false,
-        //     );
-        // }
+        match slide {
+            Slide::Static(content) if data.presenter_mode && sz.width >= PRESENTER_DUAL_PANE_MIN_WIDTH => {
+                self.paint_presenter_dual_pane(surface, &data, content);
+            }
+            Slide::Static(content) => surface.draw_surface(0, 0, content),
+            Slide::Graph(g) => g.draw(surface, 0, 0),
+        }
 
         // Slide counter
-        let info = self.data.slide_info();
-        let sz = surface.size();
+        let info = data.slide_info();
         surface.write_string(
             (sz.width as i32) - (info.len() as i32) - 2,
             (sz.height as i32) - 2,
@@ -105,27 +224,136 @@ impl OnPaint for PresentationControl {
         );
 
         // Help text
+        let help = if data.presenter_mode {
+            "◄ ► Navigate | F1 Help | F2 Overview | F5 Exit presenter | ESC Exit"
+        } else {
+            "◄ ► Navigate | F1 Help | F2 Overview | F5 Presenter | ESC Exit"
+        };
         surface.write_string(
             2,
             (sz.height as i32) - 2,
-            "◄ ► Navigate | ESC Exit",
+            help,
             CharAttribute::with_color(Color::Gray, Color::Black),
             false,
         );
+
+        if data.presenter_mode {
+            let timers = format!(
+                "Elapsed {} | Slide {}",
+                format_duration(data.elapsed_total()),
+                format_duration(data.elapsed_current_slide())
+            );
+            surface.write_string(
+                2,
+                (sz.height as i32) - 1,
+                &timers,
+                CharAttribute::with_color(Color::Yellow, Color::Black),
+                false,
+            );
+        }
+    }
+}
+
+impl PresentationControl {
+    fn paint_presenter_dual_pane(
+        &self,
+        surface: &mut Surface,
+        data: &PresentationData,
+        content: &Surface,
+    ) {
+        let sz = surface.size();
+        let main_width = (sz.width as i32 * 2) / 3;
+
+        surface.draw_surface(0, 0, content);
+
+        surface.draw_vertical_line(
+            main_width,
+            0,
+            (sz.height as i32) - 3,
+            LineType::Single,
+            CharAttribute::with_color(Color::DarkGray, Color::Black),
+        );
+
+        surface.write_string(
+            main_width + 2,
+            0,
+            "Next slide",
+            CharAttribute::with_color(Color::Gray, Color::Black),
+            false,
+        );
+
+        match data.next_slide_preview() {
+            Some(Slide::Static(next_content)) => {
+                surface.draw_surface(main_width + 2, 1, next_content);
+            }
+            Some(Slide::Graph(_)) => {
+                surface.write_string(
+                    main_width + 2,
+                    1,
+                    "(animated graph slide)",
+                    CharAttribute::with_color(Color::DarkGray, Color::Black),
+                    false,
+                );
+            }
+            None => {
+                surface.write_string(
+                    main_width + 2,
+                    1,
+                    "(end of deck)",
+                    CharAttribute::with_color(Color::DarkGray, Color::Black),
+                    false,
+                );
+            }
+        }
     }
 }
 
 impl OnKeyPressed for PresentationControl {
     fn on_key_pressed(&mut self, key: Key, _ch: char) -> EventProcessStatus {
+        let mut data = self.data.borrow_mut();
+
+        if matches!(data.overlay(), Overlay::Overview { .. }) {
+            let processed = match key.code {
+                KeyCode::Left => data.move_overview_selection(-1),
+                KeyCode::Right => data.move_overview_selection(1),
+                KeyCode::Up => data.move_overview_selection(-(OVERVIEW_COLUMNS as isize)),
+                KeyCode::Down => data.move_overview_selection(OVERVIEW_COLUMNS as isize),
+                KeyCode::Enter => data.confirm_overview_selection(),
+                KeyCode::Escape => data.close_overlay(),
+                _ => return EventProcessStatus::Ignored,
+            };
+            return if processed {
+                EventProcessStatus::Processed
+            } else {
+                EventProcessStatus::Ignored
+            };
+        }
+
+        if matches!(data.overlay(), Overlay::Help) {
+            return match key.code {
+                KeyCode::Escape | KeyCode::Enter | KeyCode::F1 => {
+                    data.close_overlay();
+                    EventProcessStatus::Processed
+                }
+                _ => EventProcessStatus::Ignored,
+            };
+        }
+
         let processed = match key.code {
-            KeyCode::Right | KeyCode::PageDown | KeyCode::Space => self.data.next_slide(),
-            KeyCode::Left | KeyCode::PageUp | KeyCode::Backspace => self.data.prev_slide(),
-            KeyCode::Home => {
-                self.data.current_slide = 0;
+            KeyCode::Right | KeyCode::PageDown | KeyCode::Space => data.next_slide(),
+            KeyCode::Left | KeyCode::PageUp | KeyCode::Backspace => data.prev_slide(),
+            KeyCode::Home => data.goto_slide(0),
+            KeyCode::End => data.goto_slide(data.slides.len().saturating_sub(1)),
+            KeyCode::F1 => {
+                data.open_help();
                 true
             }
-            KeyCode::End => {
-                self.data.current_slide = self.data.slides.len() - 1;
+            KeyCode::F2 => {
+                data.open_overview();
+                true
+            }
+            KeyCode::F5 => {
+                data.toggle_presenter_mode();
                 true
             }
             KeyCode::Escape => return EventProcessStatus::Ignored,
@@ -145,13 +373,16 @@ struct PresentationWindow {
 }
 
 impl PresentationWindow {
-    fn new() -> Self {
+    fn new(data: Rc<RefCell<PresentationData>>) -> Self {
         let mut win = Window::new(
             "Web Terminal Presentation",
             Layout::new("d:c,w:100%,h:100%"),
             window::Flags::NoCloseButton,
         );
-        let ctl = win.add(PresentationControl::new(Layout::new("d:c,w:100%,h:100%")));
+        let ctl = win.add(PresentationControl::new(
+            Layout::new("d:c,w:100%,h:100%"),
+            data,
+        ));
         PresentationWindow {
             base: win,
             presentation: ctl,
@@ -165,6 +396,8 @@ impl WindowEvents for PresentationWindow {
     }
 }
 
+const REMOTE_CONTROL_PATH: &str = "/remote";
+
 #[wasm_bindgen]
 pub fn wasm_main() {
     console_error_panic_hook::set_once();
@@ -174,7 +407,27 @@ pub fn wasm_main() {
     theme.border.focused = CharAttribute::with_color(Color::Black, Color::Black);
     theme.text.focused = CharAttribute::with_color(Color::Black, Color::Black);
 
+    let data = Rc::new(RefCell::new(PresentationData::new()));
+
+    if let Err(e) = remote::connect(REMOTE_CONTROL_PATH, data.clone()) {
+        web_sys::console::error_1(
+            &format!("Failed to open remote control socket: {e:?}").into(),
+        );
+    }
+
     let mut app = App::new().single_window().theme(theme).build().unwrap();
-    app.add_window(PresentationWindow::new());
+    app.add_window(PresentationWindow::new(data));
     app.run();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_duration_pads_to_two_digits() {
+        assert_eq!(format_duration(Duration::from_secs(5)), "00:05");
+        assert_eq!(format_duration(Duration::from_secs(65)), "01:05");
+        assert_eq!(format_duration(Duration::from_secs(3_661)), "61:01");
+    }
+}
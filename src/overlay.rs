@@ -0,0 +1,158 @@
+use appcui::prelude::*;
+
+use crate::Slide;
+
+pub(crate) const OVERVIEW_COLUMNS: usize = 5;
+const THUMBNAIL_WIDTH: i32 = 18;
+const THUMBNAIL_HEIGHT: i32 = 7;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Overlay {
+    None,
+    Overview { selected: usize },
+    Help,
+}
+
+const KEY_BINDINGS: &[(&str, &str)] = &[
+    ("Right / Space / PageDown", "Next slide"),
+    ("Left / Backspace / PageUp", "Previous slide"),
+    ("Home / End", "First / last slide"),
+    ("F1", "Toggle this help"),
+    ("F2", "Slide overview"),
+    ("F5", "Toggle presenter mode"),
+    ("Arrows (in overview)", "Move selection"),
+    ("Enter (in overview)", "Jump to selected slide"),
+    ("Escape", "Close overlay / exit"),
+];
+
+pub(crate) fn move_selection(selected: usize, slide_count: usize, delta: isize) -> usize {
+    if slide_count == 0 {
+        return 0;
+    }
+    (selected as isize + delta).clamp(0, slide_count as isize - 1) as usize
+}
+
+pub(crate) fn paint_overview(surface: &mut Surface, slides: &[Slide], selected: usize) {
+    let sz = surface.size();
+    surface.write_string(
+        2,
+        0,
+        "Slide overview — arrows to move, Enter to jump, Esc to close",
+        CharAttribute::with_color(Color::White, Color::Black),
+        false,
+    );
+
+    for (index, slide) in slides.iter().enumerate() {
+        let col = (index % OVERVIEW_COLUMNS) as i32;
+        let row = (index / OVERVIEW_COLUMNS) as i32;
+        let x = 2 + col * (THUMBNAIL_WIDTH + 2);
+        let y = 2 + row * (THUMBNAIL_HEIGHT + 1);
+        if y + THUMBNAIL_HEIGHT >= sz.height as i32 {
+            break;
+        }
+
+        let border_color = if index == selected {
+            Color::Yellow
+        } else {
+            Color::DarkGray
+        };
+        surface.draw_rect(
+            x,
+            y,
+            THUMBNAIL_WIDTH,
+            THUMBNAIL_HEIGHT,
+            LineType::Single,
+            CharAttribute::with_color(border_color, Color::Black),
+        );
+
+        paint_thumbnail(surface, slide, x + 1, y + 1, THUMBNAIL_WIDTH - 2, THUMBNAIL_HEIGHT - 2);
+
+        surface.write_string(
+            x + 1,
+            y + THUMBNAIL_HEIGHT - 1,
+            &format!("{}", index + 1),
+            CharAttribute::with_color(Color::Gray, Color::Black),
+            false,
+        );
+    }
+}
+
+fn paint_thumbnail(surface: &mut Surface, slide: &Slide, x: i32, y: i32, width: i32, height: i32) {
+    match slide {
+        Slide::Static(content) => {
+            let content_size = content.size();
+            let step_x = ((content_size.width as i32).max(1) as f32 / width as f32).max(1.0);
+            let step_y = ((content_size.height as i32).max(1) as f32 / height as f32).max(1.0);
+
+            for row in 0..height {
+                for col in 0..width {
+                    let src_x = (col as f32 * step_x) as i32;
+                    let src_y = (row as f32 * step_y) as i32;
+                    if let Some(ch) = content.char(src_x, src_y) {
+                        surface.write_char(x + col, y + row, ch);
+                    }
+                }
+            }
+        }
+        Slide::Graph(_) => {
+            surface.write_string(
+                x,
+                y,
+                "[graph]",
+                CharAttribute::with_color(Color::Aqua, Color::Black),
+                false,
+            );
+        }
+    }
+}
+
+pub(crate) fn paint_help(surface: &mut Surface) {
+    let sz = surface.size();
+    let width = 46i32;
+    let height = (KEY_BINDINGS.len() as i32) + 4;
+    let x = ((sz.width as i32) - width) / 2;
+    let y = ((sz.height as i32) - height) / 2;
+
+    surface.draw_rect(
+        x,
+        y,
+        width,
+        height,
+        LineType::Double,
+        CharAttribute::with_color(Color::White, Color::Black),
+    );
+    surface.write_string(
+        x + 2,
+        y,
+        " Key bindings ",
+        CharAttribute::with_color(Color::White, Color::Black),
+        false,
+    );
+
+    for (row, (key, action)) in KEY_BINDINGS.iter().enumerate() {
+        surface.write_string(
+            x + 2,
+            y + 2 + row as i32,
+            &format!("{key:<24} {action}"),
+            CharAttribute::with_color(Color::Gray, Color::Black),
+            false,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn move_selection_clamps_to_slide_count() {
+        assert_eq!(move_selection(0, 10, -1), 0);
+        assert_eq!(move_selection(9, 10, 1), 9);
+        assert_eq!(move_selection(3, 10, 2), 5);
+    }
+
+    #[test]
+    fn move_selection_with_no_slides_stays_at_zero() {
+        assert_eq!(move_selection(0, 0, 1), 0);
+    }
+}
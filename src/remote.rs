@@ -0,0 +1,118 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use web_sys::{MessageEvent, WebSocket};
+
+use crate::PresentationData;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum RemoteMessage {
+    Next,
+    Prev,
+    GotoSlide { index: usize },
+    Home,
+    End,
+    SlideInfo { current: usize, total: usize },
+}
+
+pub fn connect(path: &str, data: Rc<RefCell<PresentationData>>) -> Result<WebSocket, JsValue> {
+    let url = resolve_ws_url(path)?;
+    let socket = WebSocket::new(&url)?;
+    let socket_for_message = socket.clone();
+
+    let on_message = Closure::<dyn FnMut(MessageEvent)>::new(move |event: MessageEvent| {
+        let Some(text) = event.data().as_string() else {
+            return;
+        };
+
+        let message: RemoteMessage = match serde_json::from_str(&text) {
+            Ok(message) => message,
+            Err(e) => {
+                web_sys::console::error_1(&format!("Bad remote message '{text}': {e}").into());
+                return;
+            }
+        };
+
+        let mut presentation = data.borrow_mut();
+        match message {
+            RemoteMessage::Next => {
+                presentation.next_slide();
+            }
+            RemoteMessage::Prev => {
+                presentation.prev_slide();
+            }
+            RemoteMessage::GotoSlide { index } => {
+                presentation.goto_slide(index);
+            }
+            RemoteMessage::Home => {
+                presentation.goto_slide(0);
+            }
+            RemoteMessage::End => {
+                presentation.goto_slide(presentation.slide_count().saturating_sub(1));
+            }
+            RemoteMessage::SlideInfo { .. } => {
+                // Status replies are only ever sent by us, never acted on.
+                return;
+            }
+        }
+
+        broadcast_slide_info(&socket_for_message, &presentation);
+    });
+
+    socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+    on_message.forget();
+
+    Ok(socket)
+}
+
+// WebSocket::new requires a ws:/wss: scheme; a relative "/remote" is resolved
+// against the page's http(s) origin and throws a SyntaxError. Build the full
+// URL from the page's own location instead, matching its scheme and host.
+fn resolve_ws_url(path: &str) -> Result<String, JsValue> {
+    let location = web_sys::window()
+        .ok_or_else(|| JsValue::from_str("no global window"))?
+        .location();
+    let protocol = location.protocol()?;
+    let ws_protocol = if protocol == "https:" { "wss" } else { "ws" };
+    let host = location.host()?;
+    Ok(format!("{ws_protocol}://{host}{path}"))
+}
+
+fn broadcast_slide_info(socket: &WebSocket, data: &PresentationData) {
+    let (current, total) = data.slide_position();
+    let status = RemoteMessage::SlideInfo { current, total };
+    if let Ok(json) = serde_json::to_string(&status) {
+        let _ = socket.send_with_str(&json);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(message: RemoteMessage, json: &str) {
+        assert_eq!(serde_json::to_string(&message).unwrap(), json);
+        let parsed: RemoteMessage = serde_json::from_str(json).unwrap();
+        assert_eq!(format!("{parsed:?}"), format!("{message:?}"));
+    }
+
+    #[test]
+    fn remote_message_wire_format() {
+        round_trip(RemoteMessage::Next, r#"{"type":"Next"}"#);
+        round_trip(RemoteMessage::Prev, r#"{"type":"Prev"}"#);
+        round_trip(
+            RemoteMessage::GotoSlide { index: 3 },
+            r#"{"type":"GotoSlide","index":3}"#,
+        );
+        round_trip(RemoteMessage::Home, r#"{"type":"Home"}"#);
+        round_trip(RemoteMessage::End, r#"{"type":"End"}"#);
+        round_trip(
+            RemoteMessage::SlideInfo { current: 2, total: 10 },
+            r#"{"type":"SlideInfo","current":2,"total":10}"#,
+        );
+    }
+}
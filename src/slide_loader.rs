@@ -0,0 +1,118 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use appcui::prelude::*;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Request, RequestInit, RequestMode, Response};
+
+use crate::graph::{GraphDef, GraphSlide};
+use crate::{PresentationData, Slide};
+
+const MANIFEST_URL: &str = "slides/manifest.json";
+
+pub fn spawn_slide_loader(data: Rc<RefCell<PresentationData>>) {
+    wasm_bindgen_futures::spawn_local(async move {
+        let manifest = match fetch_manifest(MANIFEST_URL).await {
+            Ok(files) => files,
+            Err(e) => {
+                web_sys::console::error_1(
+                    &format!("Failed to load slide manifest '{MANIFEST_URL}': {e:?}").into(),
+                );
+                return;
+            }
+        };
+
+        for file in manifest {
+            let url = format!("slides/{file}");
+            let slide = if file.ends_with(".graph.json") {
+                fetch_graph_slide(&url, &file).await
+            } else {
+                fetch_static_slide(&url, &file).await
+            };
+
+            data.borrow_mut().push_slide(slide);
+        }
+    });
+}
+
+async fn fetch_static_slide(url: &str, file: &str) -> Slide {
+    match fetch_slide_bytes(url).await {
+        Ok(bytes) => Slide::Static(Surface::from_buffer(&bytes).unwrap_or_else(|e| {
+            web_sys::console::error_1(&format!("Failed to decode slide '{file}': {e}").into());
+            placeholder_surface(&format!("Failed to load slide: {file}"))
+        })),
+        Err(e) => {
+            web_sys::console::error_1(&format!("Failed to fetch slide '{file}': {e:?}").into());
+            Slide::Static(placeholder_surface(&format!("Failed to load slide: {file}")))
+        }
+    }
+}
+
+async fn fetch_graph_slide(url: &str, file: &str) -> Slide {
+    match fetch_text(url).await.and_then(|text| {
+        serde_json::from_str::<GraphDef>(&text).map_err(|e| JsValue::from_str(&e.to_string()))
+    }) {
+        Ok(def) => Slide::Graph(GraphSlide::new(&def)),
+        Err(e) => {
+            web_sys::console::error_1(&format!("Failed to load graph slide '{file}': {e:?}").into());
+            Slide::Static(placeholder_surface(&format!("Failed to load slide: {file}")))
+        }
+    }
+}
+
+async fn fetch_manifest(url: &str) -> Result<Vec<String>, JsValue> {
+    let text = fetch_text(url).await?;
+    let value: serde_json::Value =
+        serde_json::from_str(&text).map_err(|e| JsValue::from_str(&e.to_string()))?;
+    let files = value
+        .as_array()
+        .ok_or_else(|| JsValue::from_str("manifest is not a JSON array"))?
+        .iter()
+        .filter_map(|v| v.as_str().map(str::to_owned))
+        .collect();
+    Ok(files)
+}
+
+async fn fetch_text(url: &str) -> Result<String, JsValue> {
+    let response = fetch(url).await?;
+    let text = JsFuture::from(response.text()?).await?;
+    Ok(text.as_string().unwrap_or_default())
+}
+
+async fn fetch_slide_bytes(url: &str) -> Result<Vec<u8>, JsValue> {
+    let response = fetch(url).await?;
+    let buffer = JsFuture::from(response.array_buffer()?).await?;
+    let bytes = js_sys::Uint8Array::new(&buffer).to_vec();
+    Ok(bytes)
+}
+
+async fn fetch(url: &str) -> Result<Response, JsValue> {
+    let mut opts = RequestInit::new();
+    opts.method("GET");
+    opts.mode(RequestMode::SameOrigin);
+
+    let request = Request::new_with_str_and_init(url, &opts)?;
+
+    let window = web_sys::window().ok_or_else(|| JsValue::from_str("no global window"))?;
+    let response_value = JsFuture::from(window.fetch_with_request(&request)).await?;
+    response_value.dyn_into::<Response>()
+}
+
+fn placeholder_surface(message: &str) -> Surface {
+    let mut surface = Surface::new(80, 24);
+    surface.clear(Character::new(
+        ' ',
+        Color::Black,
+        Color::Black,
+        CharFlags::None,
+    ));
+    surface.write_string(
+        2,
+        2,
+        message,
+        CharAttribute::with_color(Color::Red, Color::Black),
+        false,
+    );
+    surface
+}